@@ -0,0 +1,41 @@
+use core::char::ToLowercase;
+use core::str::Chars;
+
+/// Iterator over the full Unicode case-folded expansion of a single `char`,
+/// drained one char at a time by [`crate::stream::SlugChars`] (the crate's
+/// one per-char transform, which [`crate::slugify_with`] also collects from)
+/// so the special-case table only lives here.
+pub enum FoldChars {
+    Literal(Chars<'static>),
+    Lower(ToLowercase),
+}
+
+impl Iterator for FoldChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            FoldChars::Literal(it) => it.next(),
+            FoldChars::Lower(it) => it.next(),
+        }
+    }
+}
+
+/// Return the case-folded expansion of `ch` as a `char` iterator.
+///
+/// This differs from `char::to_lowercase` for the handful of characters with
+/// a one-to-many or many-to-one folding; see
+/// [`crate::SlugOptions::case_fold`] for why that matters. "ß"/"ẞ"
+/// fold to "ss" (plain `to_lowercase` maps capital sharp S "ẞ" to "ß", not
+/// "ss", which would break the guarantee described there), and final sigma
+/// "ς" folds to the same thing as "Σ"/"σ". Everything else falls back to
+/// `to_lowercase`, which already agrees with full folding for the vast
+/// majority of scripts (including Cyrillic, which has no special folding
+/// quirks of its own).
+pub fn fold_chars(ch: char) -> FoldChars {
+    match ch {
+        'ß' | 'ẞ' => FoldChars::Literal("ss".chars()),
+        'ς' => FoldChars::Literal("σ".chars()),
+        _ => FoldChars::Lower(ch.to_lowercase()),
+    }
+}
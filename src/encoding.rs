@@ -0,0 +1,285 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::string::String;
+
+use crate::{slugify_with, SlugOptions};
+
+/// Decode raw, not-necessarily-UTF-8 bytes with a lightweight charset
+/// sniffer, then run the result through [`crate::slugify_with`].
+///
+/// Detection order:
+/// 1. UTF-8/UTF-16 byte-order mark, if present.
+/// 2. Strict UTF-8 validation.
+/// 3. The best-scoring legacy guess among Windows-1251, Windows-1252,
+///    ISO-8859-1 and Shift-JIS (see [`best_legacy_encoding`]).
+///
+/// This is meant for filenames pulled out of ZIP entries, HTTP headers, or
+/// legacy filesystem metadata that carries no charset label of its own.
+#[must_use]
+pub fn slugify_bytes(input: &[u8], opt: SlugOptions) -> String {
+    slugify_with(&decode(input), opt)
+}
+
+fn decode(input: &[u8]) -> String {
+    if let Some(rest) = input.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8_lossy(rest).into_owned();
+    }
+    if let Some(rest) = input.strip_prefix(&[0xFF, 0xFE]) {
+        return decode_utf16(rest, u16::from_le_bytes);
+    }
+    if let Some(rest) = input.strip_prefix(&[0xFE, 0xFF]) {
+        return decode_utf16(rest, u16::from_be_bytes);
+    }
+    if let Ok(s) = core::str::from_utf8(input) {
+        return s.into();
+    }
+
+    match best_legacy_encoding(input) {
+        LegacyEncoding::Windows1251 => decode_with(input, windows1251_char),
+        LegacyEncoding::Windows1252 => decode_with(input, windows1252_char),
+        LegacyEncoding::Iso8859_1 => decode_with(input, char::from),
+        LegacyEncoding::ShiftJis => decode_shift_jis(input),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> String {
+    let units = bytes.chunks_exact(2).map(|c| to_u16([c[0], c[1]]));
+    char::decode_utf16(units).map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+}
+
+fn decode_with(bytes: &[u8], to_char: impl Fn(u8) -> char) -> String {
+    bytes.iter().map(|&b| to_char(b)).collect()
+}
+
+#[derive(Clone, Copy, Debug)]
+enum LegacyEncoding {
+    Windows1251,
+    Windows1252,
+    Iso8859_1,
+    ShiftJis,
+}
+
+/// Score each candidate legacy encoding by how plausible its decode looks
+/// and return the highest scorer. This is a heuristic, not a real charset
+/// detector: it penalizes byte sequences that are implausible for a given
+/// encoding (stray C1 control bytes, orphaned Shift-JIS lead/trail bytes)
+/// and rewards sequences that look like real text in that encoding.
+fn best_legacy_encoding(bytes: &[u8]) -> LegacyEncoding {
+    let candidates = [
+        (LegacyEncoding::Windows1251, score_windows1251(bytes)),
+        (LegacyEncoding::Windows1252, score_windows1252(bytes)),
+        (LegacyEncoding::Iso8859_1, score_iso8859_1(bytes)),
+        (LegacyEncoding::ShiftJis, score_shift_jis(bytes)),
+    ];
+    candidates
+        .into_iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(LegacyEncoding::Iso8859_1, |(enc, _)| enc)
+}
+
+fn score_windows1251(bytes: &[u8]) -> f32 {
+    let mut score = 0.0;
+    for &b in bytes {
+        match b {
+            0xC0..=0xFF => score += 1.0,                 // А-Я, а-я
+            0xA0..=0xBF => score += 0.5,                 // Ukrainian letters & punctuation
+            0x80..=0x9F => score -= 1.0,                 // rarely-used control-range slots
+            _ => {}
+        }
+    }
+    score / bytes.len().max(1) as f32
+}
+
+fn score_windows1252(bytes: &[u8]) -> f32 {
+    let mut score = 0.0;
+    for &b in bytes {
+        match b {
+            0xA0..=0xFF => score += 1.0, // Latin-1-ish letters/punctuation
+            0x80..=0x9F => score += 0.25, // printable smart quotes/dashes/€
+            _ => {}
+        }
+    }
+    score / bytes.len().max(1) as f32 - accented_run_penalty(bytes)
+}
+
+fn score_iso8859_1(bytes: &[u8]) -> f32 {
+    let mut score = 0.0;
+    for &b in bytes {
+        match b {
+            0xA0..=0xFF => score += 1.0,
+            0x80..=0x9F => score -= 1.0, // C1 controls, rarely intentional
+            _ => {}
+        }
+    }
+    score / bytes.len().max(1) as f32 - accented_run_penalty(bytes)
+}
+
+/// Penalize runs of back-to-back accented-letter bytes (0xC0-0xFF): common
+/// in Cyrillic text (the whole alphabet lives there), but unusual in real
+/// Latin-script text, where accents decorate occasional letters rather than
+/// whole runs. Used to break ties against Windows-1251 on pure-high-byte input.
+fn accented_run_penalty(bytes: &[u8]) -> f32 {
+    let mut penalty = 0.0;
+    for pair in bytes.windows(2) {
+        if matches!(pair[0], 0xC0..=0xFF) && matches!(pair[1], 0xC0..=0xFF) {
+            penalty += 0.3;
+        }
+    }
+    penalty / bytes.len().max(1) as f32
+}
+
+fn score_shift_jis(bytes: &[u8]) -> f32 {
+    let mut score = 0.0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if matches!(b, 0x81..=0x9F | 0xE0..=0xFC) {
+            match bytes.get(i + 1) {
+                Some(&(0x40..=0x7E | 0x80..=0xFC)) => {
+                    // Kept below 1.0/byte so an unrelated but byte-plausible
+                    // encoding (e.g. Cyrillic Windows-1251, whose whole
+                    // alphabet also sits in 0xC0-0xFF) doesn't lose ties here.
+                    score += 1.4;
+                    i += 2;
+                    continue;
+                }
+                _ => score -= 2.0, // lead byte with no valid trail byte
+            }
+        } else if matches!(b, 0xA1..=0xDF) {
+            score += 0.5; // half-width katakana
+        }
+        i += 1;
+    }
+    score / bytes.len().max(1) as f32
+}
+
+fn windows1251_char(b: u8) -> char {
+    match b {
+        0x80 => '\u{0402}',
+        0x81 => '\u{0403}',
+        0x82 => '\u{201A}',
+        0x83 => '\u{0453}',
+        0x84 => '\u{201E}',
+        0x85 => '\u{2026}',
+        0x86 => '\u{2020}',
+        0x87 => '\u{2021}',
+        0x88 => '\u{20AC}',
+        0x89 => '\u{2030}',
+        0x8A => '\u{0409}',
+        0x8B => '\u{2039}',
+        0x8C => '\u{040A}',
+        0x8D => '\u{040C}',
+        0x8E => '\u{040B}',
+        0x8F => '\u{040F}',
+        0x90 => '\u{0452}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201C}',
+        0x94 => '\u{201D}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x99 => '\u{2122}',
+        0x9A => '\u{0459}',
+        0x9B => '\u{203A}',
+        0x9C => '\u{045A}',
+        0x9D => '\u{045C}',
+        0x9E => '\u{045B}',
+        0x9F => '\u{045F}',
+        0xA0 => '\u{00A0}',
+        0xA1 => '\u{040E}',
+        0xA2 => '\u{045E}',
+        0xA3 => '\u{0408}',
+        0xA4 => '\u{00A4}',
+        0xA5 => '\u{0490}',
+        0xA6 => '\u{00A6}',
+        0xA7 => '\u{00A7}',
+        0xA8 => '\u{0401}',
+        0xA9 => '\u{00A9}',
+        0xAA => '\u{0404}',
+        0xAB => '\u{00AB}',
+        0xAC => '\u{00AC}',
+        0xAD => '\u{00AD}',
+        0xAE => '\u{00AE}',
+        0xAF => '\u{0407}',
+        0xB0 => '\u{00B0}',
+        0xB1 => '\u{00B1}',
+        0xB2 => '\u{0406}',
+        0xB3 => '\u{0456}',
+        0xB4 => '\u{0491}',
+        0xB5 => '\u{00B5}',
+        0xB6 => '\u{00B6}',
+        0xB7 => '\u{00B7}',
+        0xB8 => '\u{0451}',
+        0xB9 => '\u{2116}',
+        0xBA => '\u{0454}',
+        0xBB => '\u{00BB}',
+        0xBC => '\u{0458}',
+        0xBD => '\u{0405}',
+        0xBE => '\u{0455}',
+        0xBF => '\u{0457}',
+        0xC0..=0xFF => char::from_u32(0x0410 + u32::from(b - 0xC0)).unwrap_or('\u{FFFD}'),
+        _ => char::from(b),
+    }
+}
+
+fn windows1252_char(b: u8) -> char {
+    match b {
+        0x80 => '\u{20AC}',
+        0x82 => '\u{201A}',
+        0x83 => '\u{0192}',
+        0x84 => '\u{201E}',
+        0x85 => '\u{2026}',
+        0x86 => '\u{2020}',
+        0x87 => '\u{2021}',
+        0x88 => '\u{02C6}',
+        0x89 => '\u{2030}',
+        0x8A => '\u{0160}',
+        0x8B => '\u{2039}',
+        0x8C => '\u{0152}',
+        0x8E => '\u{017D}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201C}',
+        0x94 => '\u{201D}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x98 => '\u{02DC}',
+        0x99 => '\u{2122}',
+        0x9A => '\u{0161}',
+        0x9B => '\u{203A}',
+        0x9C => '\u{0153}',
+        0x9E => '\u{017E}',
+        0x9F => '\u{0178}',
+        _ => char::from(b),
+    }
+}
+
+/// Decode Shift-JIS. This is a practical subset, not a full JIS X 0208
+/// table: ASCII (with the classic JIS X 0201 quirks of `\` -> yen sign and
+/// `~` -> overline) and half-width katakana decode exactly; two-byte
+/// kanji/kana sequences are consumed as a pair but rendered as the
+/// replacement character, since spelling out the full double-byte table
+/// isn't worth it for a "detect it's Japanese-ish, slugify it" sniffer.
+fn decode_shift_jis(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            0x5C => out.push('\u{00A5}'), // yen sign
+            0x7E => out.push('\u{203E}'), // overline
+            0x00..=0x7F => out.push(char::from(b)),
+            0xA1..=0xDF => out.push(char::from_u32(0xFF61 + u32::from(b - 0xA1)).unwrap_or('\u{FFFD}')),
+            0x81..=0x9F | 0xE0..=0xFC if i + 1 < bytes.len() => {
+                out.push('\u{FFFD}');
+                i += 1; // consume the trail byte too
+            }
+            _ => out.push('\u{FFFD}'),
+        }
+        i += 1;
+    }
+    out
+}
@@ -10,11 +10,25 @@ use alloc::string::String;
 use std::string::String;
 
 mod options;
-pub use options::SlugOptions;
+pub use options::{Romanization, SlugOptions};
+
+#[cfg(feature = "normalize")]
+mod normalize;
+
+#[cfg(feature = "unicode")]
+mod casefold;
 
 #[cfg(feature = "transliterate")]
 mod translit;
 
+#[cfg(feature = "detect-encoding")]
+mod encoding;
+#[cfg(feature = "detect-encoding")]
+pub use encoding::slugify_bytes;
+
+mod stream;
+pub use stream::{slugify_chars, SlugChars};
+
 /// Convert any string-like input into a safe filename slug
 /// with default options.
 ///
@@ -50,85 +64,20 @@ pub fn slugify<S: AsRef<str>>(input: S) -> String {
 pub fn slugify_with(input: &str, opt: SlugOptions) -> String {
     let sep = sanitize_separator(opt.separator);
 
-    let mut out = String::with_capacity(core::cmp::min(input.len(), opt.max_len_bytes));
-    let mut last_was_sep = true; // leading seps will be trimmed
-
-    for ch in input.chars() {
-        // Hard forbidden filename chars -> separator boundary
-        if is_forbidden_filename_char(ch) {
-            push_sep(&mut out, sep, &mut last_was_sep);
-            continue;
-        }
-
-        // ASCII fast path
-        if ch.is_ascii_alphanumeric() {
-            push_ascii_alnum(&mut out, ch, opt.lowercase);
-            last_was_sep = false;
-            continue;
-        }
-
-        // underscore policy
-        if ch == '_' && opt.keep_underscore {
-            out.push('_');
-            last_was_sep = false;
-            continue;
-        }
-
-        // Unicode keep-as-is
-        #[cfg(feature = "unicode")]
-        {
-            if opt.allow_unicode && ch.is_alphanumeric() {
-                if opt.lowercase {
-                    for lc in ch.to_lowercase() {
-                        out.push(lc);
-                    }
-                } else {
-                    out.push(ch);
-                }
-                last_was_sep = false;
-                continue;
-            }
-        }
-
-        // Transliteration into ASCII
-        #[cfg(feature = "transliterate")]
-        {
-            if let Some(s) = translit::transliterate(ch, opt.lowercase) {
-                if s.is_empty() {
-                    continue;
-                }
-                let mut pushed_any = false;
-                for t in s.chars() {
-                    if t.is_ascii_alphanumeric() {
-                        push_ascii_alnum(&mut out, t, opt.lowercase);
-                        last_was_sep = false;
-                        pushed_any = true;
-                    } else if t == '_' && opt.keep_underscore {
-                        out.push('_');
-                        last_was_sep = false;
-                        pushed_any = true;
-                    } else if is_separatorish(t) {
-                        push_sep(&mut out, sep, &mut last_was_sep);
-                    } else {
-                        // anything weird from transliteration => separator
-                        push_sep(&mut out, sep, &mut last_was_sep);
-                    }
-                }
-                if pushed_any {
-                    continue;
-                }
-            }
-        }
-
-        // Common separators & whitespace -> separator
-        if is_separatorish(ch) {
-            push_sep(&mut out, sep, &mut last_was_sep);
-            continue;
-        }
-
-        // Everything else -> separator
-        push_sep(&mut out, sep, &mut last_was_sep);
-    }
+    // Decompose and strip combining marks before the per-char transform, so
+    // that accented codepoints not covered by `translit`'s fixed table (or
+    // that simply arrived pre-decomposed) still fall through to their base
+    // letter.
+    #[cfg(feature = "normalize")]
+    let normalized = normalize::normalize(input);
+    #[cfg(feature = "normalize")]
+    let input = normalized.as_str();
+
+    // The per-char transform itself lives in `slugify_chars`; everything
+    // below is the adapter layer `slugify_chars`'s docs describe: passes
+    // that need to see the whole output (or count whole bytes) rather than
+    // one char at a time.
+    let mut out: String = slugify_chars(input.chars(), opt).collect();
 
     // Windows quirks: trailing dot/space invalid; also trim trailing separators
     trim_end_seps_dots_spaces(&mut out, sep);
@@ -172,23 +121,6 @@ const fn sanitize_separator(sep: char) -> char {
     }
 }
 
-#[inline]
-fn push_sep(out: &mut String, sep: char, last_was_sep: &mut bool) {
-    if !*last_was_sep && !out.is_empty() {
-        out.push(sep);
-        *last_was_sep = true;
-    }
-}
-
-#[inline]
-fn push_ascii_alnum(out: &mut String, ch: char, lowercase: bool) {
-    if lowercase {
-        out.push(ch.to_ascii_lowercase());
-    } else {
-        out.push(ch);
-    }
-}
-
 #[inline]
 fn is_separatorish(ch: char) -> bool {
     // NOTE: '.' treated as separatorish because Windows forbids trailing '.' and it is often a boundary in filenames
@@ -0,0 +1,130 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Run a string through NFKD-style decomposition and drop combining marks
+/// (Unicode category Mn), leaving the base letters behind.
+///
+/// This is intentionally a hand-written subset of the real Unicode
+/// decomposition tables rather than a full implementation: it covers the
+/// Latin-1 Supplement, Latin Extended-A, Latin Extended Additional
+/// (Vietnamese) diacritics, and the common compatibility ligatures
+/// ("ﬁ", "ﬂ", ...). Latin Extended-B (e.g. "ǎ") has no entries. Anything
+/// outside that is passed through unchanged unless it is itself a combining
+/// mark, in which case it is dropped.
+///
+/// Run this *before* `transliterate`/`allow_unicode` handling: the base
+/// letters it produces are plain Latin letters that those branches already
+/// understand, so no new table entries are needed there.
+pub fn normalize(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match decompose(ch) {
+            Some(expansion) => {
+                for c in expansion.chars() {
+                    if !is_combining_mark(c) {
+                        out.push(c);
+                    }
+                }
+            }
+            None => {
+                if !is_combining_mark(ch) {
+                    out.push(ch);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Unicode category Mn (combining marks) in the blocks that show up when
+/// decomposing Latin/Cyrillic/Vietnamese diacritics, or when the caller
+/// already handed us NFD input.
+#[inline]
+fn is_combining_mark(ch: char) -> bool {
+    matches!(ch as u32,
+        0x0300..=0x036F // Combining Diacritical Marks
+        | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+        | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+        | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+        | 0xFE20..=0xFE2F // Combining Half Marks
+    )
+}
+
+/// Return the decomposition of `ch`, or `None` if `ch` has no decomposition
+/// we know about (caller should keep `ch` as-is).
+///
+/// Only the base letters are returned (never actual combining-mark
+/// codepoints), since `normalize` would strip them right back out anyway.
+fn decompose(ch: char) -> Option<&'static str> {
+    let cp = ch as u32;
+    Some(match cp {
+        // Latin-1 Supplement: grave/acute/circumflex/tilde/diaeresis/ring.
+        0x00C0..=0x00C5 => "A",
+        0x00C7 => "C",
+        0x00C8..=0x00CB => "E",
+        0x00CC..=0x00CF => "I",
+        0x00D1 => "N",
+        0x00D2..=0x00D6 => "O",
+        0x00D9..=0x00DC => "U",
+        0x00DD => "Y",
+        0x00E0..=0x00E5 => "a",
+        0x00E7 => "c",
+        0x00E8..=0x00EB => "e",
+        0x00EC..=0x00EF => "i",
+        0x00F1 => "n",
+        0x00F2..=0x00F6 => "o",
+        0x00F9..=0x00FC => "u",
+        0x00FD | 0x00FF => "y",
+
+        // Latin Extended-A (0100-017F): near-strictly alternating
+        // upper/lower pairs of {base letter}+{diacritic} per letter group.
+        0x0100..=0x0105 => if cp.is_multiple_of(2) { "A" } else { "a" },
+        0x0106..=0x010D => if cp.is_multiple_of(2) { "C" } else { "c" },
+        0x010E..=0x0111 => if cp.is_multiple_of(2) { "D" } else { "d" },
+        0x0112..=0x011B => if cp.is_multiple_of(2) { "E" } else { "e" },
+        0x011C..=0x0123 => if cp.is_multiple_of(2) { "G" } else { "g" },
+        0x0124..=0x0127 => if cp.is_multiple_of(2) { "H" } else { "h" },
+        0x0128..=0x012F => if cp.is_multiple_of(2) { "I" } else { "i" },
+        0x0130 => "I",
+        0x0131 => "i",
+        0x0134..=0x0135 => if cp.is_multiple_of(2) { "J" } else { "j" },
+        0x0136..=0x0137 => if cp.is_multiple_of(2) { "K" } else { "k" },
+        0x0139..=0x0142 => if cp % 2 == 1 { "L" } else { "l" },
+        0x0143..=0x0148 => if cp % 2 == 1 { "N" } else { "n" },
+        0x014C..=0x0151 => if cp.is_multiple_of(2) { "O" } else { "o" },
+        0x0152 => "OE",
+        0x0153 => "oe",
+        0x0154..=0x0159 => if cp.is_multiple_of(2) { "R" } else { "r" },
+        0x015A..=0x0161 => if cp.is_multiple_of(2) { "S" } else { "s" },
+        0x0162..=0x0167 => if cp.is_multiple_of(2) { "T" } else { "t" },
+        0x0168..=0x0173 => if cp.is_multiple_of(2) { "U" } else { "u" },
+        0x0174 => "W",
+        0x0175 => "w",
+        0x0176 | 0x0178 => "Y",
+        0x0177 => "y",
+        0x0179..=0x017E => if cp % 2 == 1 { "Z" } else { "z" },
+        0x017F => "s",
+
+        // Latin Extended Additional (1E00-1EFF): Vietnamese tone marks
+        // layered on circumflex/breve/horn bases, grouped by base vowel.
+        0x1EA0..=0x1EB7 => if cp.is_multiple_of(2) { "A" } else { "a" },
+        0x1EB8..=0x1EC7 => if cp.is_multiple_of(2) { "E" } else { "e" },
+        0x1EC8..=0x1ECB => if cp.is_multiple_of(2) { "I" } else { "i" },
+        0x1ECC..=0x1EE3 => if cp.is_multiple_of(2) { "O" } else { "o" },
+        0x1EE4..=0x1EF1 => if cp.is_multiple_of(2) { "U" } else { "u" },
+        0x1EF2..=0x1EF9 => if cp.is_multiple_of(2) { "Y" } else { "y" },
+
+        // Compatibility ligatures (NFKD, not NFD, but callers expect them
+        // to fall out of a "normalize" pass too).
+        0xFB00 => "ff",
+        0xFB01 => "fi",
+        0xFB02 => "fl",
+        0xFB03 => "ffi",
+        0xFB04 => "ffl",
+        0xFB05 | 0xFB06 => "st",
+
+        _ => return None,
+    })
+}
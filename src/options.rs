@@ -1,3 +1,26 @@
+/// Cyrillic romanization scheme used by `transliterate` (feature
+/// `transliterate`). Only affects Cyrillic input; Latin/Greek/etc.
+/// transliteration is scheme-independent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Romanization {
+    /// The crate's original mixed Russian/Ukrainian table, kept as the
+    /// default so existing output doesn't change.
+    #[default]
+    Auto,
+    /// BGN/PCGN romanization of Russian.
+    RussianBgnPcgn,
+    /// Ukrainian national romanization (e.g. "и" -> "y", "г" -> "h",
+    /// "є" -> "ie"). Does not special-case word-initial "г"/"Г".
+    UkrainianNational,
+    /// ISO 9 "scientific" transliteration, ASCII-folded: diacritics are
+    /// dropped to stay ASCII, with digraphs for "ж"/"ч"/"ш"/"щ" (e.g.
+    /// "ж" -> "zh") so they stay distinct from "з"/"ц"/"с". Other letters
+    /// whose ISO 9 forms differ only by a diacritic share one ASCII form
+    /// (e.g. "у"/"ю" -> "u", "а"/"я" -> "a"), so the mapping is not
+    /// one-to-one.
+    Iso9,
+}
+
 #[derive(Clone, Copy, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct SlugOptions {
@@ -23,6 +46,20 @@ pub struct SlugOptions {
     /// Fallback name for empty / "." / ".." results.
     /// Default: "file"
     pub fallback: &'static str,
+    /// If true, insert a separator at camelCase/PascalCase word boundaries
+    /// (e.g. "HelloWorld" -> "hello-world", "XMLParser" -> "xml-parser")
+    /// before the rest of the per-char handling runs.
+    /// Default: false
+    pub split_word_case: bool,
+    /// Cyrillic romanization scheme (feature `transliterate`).
+    /// Default: `Romanization::Auto`
+    pub scheme: Romanization,
+    /// If true, lowercase Unicode input kept by `allow_unicode` (feature
+    /// `unicode`) using full Unicode case folding instead of simple
+    /// lowercasing, so strings differing only by case produce
+    /// byte-identical slugs. Has no effect unless `allow_unicode` is set.
+    /// Default: false
+    pub case_fold: bool,
 }
 
 impl Default for SlugOptions {
@@ -35,6 +72,9 @@ impl Default for SlugOptions {
             keep_underscore: true, // practical default for filenames
             avoid_leading_dot: true,
             fallback: "file",
+            split_word_case: false,
+            scheme: Romanization::Auto,
+            case_fold: false,
         }
     }
 }
@@ -0,0 +1,220 @@
+use core::iter::Peekable;
+
+use crate::{is_forbidden_filename_char, is_separatorish, sanitize_separator, SlugOptions};
+
+/// Max number of chars a single source char can expand into before being
+/// drained by the caller. The longest current expansion is transliteration's
+/// "shch" (4 chars); 8 leaves headroom without reaching for a heap buffer.
+const PENDING_CAP: usize = 8;
+
+/// Lazily slugify a `char` iterator without building a `String`, for
+/// `no_std`/embedded callers who want to stream into a fixed buffer or a
+/// hasher instead of allocating.
+///
+/// This is the crate's one implementation of the per-char transform
+/// (forbidden-char boundaries, ASCII/underscore/unicode/transliteration
+/// handling, separator collapsing, `split_word_case`); [`crate::slugify_with`]
+/// is just `slugify_chars(input.chars(), opt).collect()` plus the
+/// whole-output adapters below, so there's exactly one place this logic
+/// lives. Trailing separator trimming is done the streaming way: a pending
+/// separator is held back and only emitted once a real character follows,
+/// so it's simply never emitted if the input ends first.
+///
+/// What it does *not* do, because they need to see the whole output first:
+/// leading-dot avoidance, Windows-reserved-name detection, `max_len_bytes`
+/// truncation, and the empty/"."/".." fallback. Those remain adapters a
+/// caller (or [`crate::slugify_with`]) layers on top of this iterator:
+/// buffer the first few chars to check reserved names, and count emitted
+/// bytes to enforce a length cap.
+///
+/// The decomposition pass of feature `normalize` is not applied here either:
+/// [`crate::slugify_with`] runs it over the whole input string before
+/// handing its chars to this iterator, and it is not exposed to other
+/// callers.
+#[must_use]
+pub fn slugify_chars<I: Iterator<Item = char>>(iter: I, opt: SlugOptions) -> SlugChars<I> {
+    SlugChars {
+        inner: iter.peekable(),
+        opt,
+        pending: PendingQueue::new(),
+        held_sep: false,
+        emitted_any: false,
+        prev_ch: None,
+        digit_run_after_lower: false,
+    }
+}
+
+/// Iterator returned by [`slugify_chars`].
+pub struct SlugChars<I: Iterator<Item = char>> {
+    inner: Peekable<I>,
+    opt: SlugOptions,
+    pending: PendingQueue,
+    held_sep: bool,
+    emitted_any: bool,
+    prev_ch: Option<char>,
+    /// Whether the run of digits `prev_ch` is part of (if any) started
+    /// right after a lowercase letter, as opposed to an uppercase one.
+    /// Carried across consecutive digits; see `process` for why this
+    /// distinction matters for `split_word_case`.
+    digit_run_after_lower: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for SlugChars<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(ch) = self.pending.pop() {
+                return Some(ch);
+            }
+            let ch = self.inner.next()?;
+            self.process(ch);
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> SlugChars<I> {
+    fn process(&mut self, ch: char) {
+        if self.opt.split_word_case {
+            if let Some(prev) = self.prev_ch {
+                // A digit run only counts as "lowercase" for boundary
+                // purposes if it started after a lowercase letter ("sha256"
+                // -> "Hash" still splits), not after an uppercase run
+                // ("XML2" -> "JSON" stays glued, matching "parseXML2JSON" ->
+                // "parse-xml2json").
+                let lower_then_upper =
+                    (prev.is_lowercase() || (prev.is_ascii_digit() && self.digit_run_after_lower)) && ch.is_uppercase();
+                let acronym_then_word =
+                    ch.is_uppercase() && prev.is_uppercase() && self.inner.peek().is_some_and(|next| next.is_lowercase());
+                if lower_then_upper || acronym_then_word {
+                    self.mark_sep();
+                }
+            }
+        }
+        if ch.is_alphabetic() {
+            self.digit_run_after_lower = ch.is_lowercase();
+        }
+        self.prev_ch = Some(ch);
+
+        if is_forbidden_filename_char(ch) {
+            self.mark_sep();
+            return;
+        }
+
+        if ch.is_ascii_alphanumeric() {
+            self.flush_sep();
+            self.push_ascii(ch);
+            return;
+        }
+
+        if ch == '_' && self.opt.keep_underscore {
+            self.flush_sep();
+            self.pending.push('_');
+            self.emitted_any = true;
+            return;
+        }
+
+        #[cfg(feature = "unicode")]
+        if self.opt.allow_unicode && ch.is_alphanumeric() {
+            self.flush_sep();
+            if self.opt.lowercase {
+                if self.opt.case_fold {
+                    for c in crate::casefold::fold_chars(ch) {
+                        self.pending.push(c);
+                    }
+                } else {
+                    for lc in ch.to_lowercase() {
+                        self.pending.push(lc);
+                    }
+                }
+            } else {
+                self.pending.push(ch);
+            }
+            self.emitted_any = true;
+            return;
+        }
+
+        #[cfg(feature = "transliterate")]
+        if let Some(s) = crate::translit::transliterate(ch, self.opt.lowercase, self.opt.scheme) {
+            for t in s.chars() {
+                if t.is_ascii_alphanumeric() {
+                    self.flush_sep();
+                    self.push_ascii(t);
+                } else if t == '_' && self.opt.keep_underscore {
+                    self.flush_sep();
+                    self.pending.push('_');
+                    self.emitted_any = true;
+                } else {
+                    // separatorish, or anything else weird from transliteration
+                    self.mark_sep();
+                }
+            }
+            return;
+        }
+
+        if is_separatorish(ch) {
+            self.mark_sep();
+            return;
+        }
+
+        // Everything else -> separator
+        self.mark_sep();
+    }
+
+    #[inline]
+    fn mark_sep(&mut self) {
+        self.held_sep = true;
+    }
+
+    #[inline]
+    fn flush_sep(&mut self) {
+        if self.held_sep {
+            if self.emitted_any {
+                self.pending.push(sanitize_separator(self.opt.separator));
+            }
+            self.held_sep = false;
+        }
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, ch: char) {
+        if self.opt.lowercase {
+            self.pending.push(ch.to_ascii_lowercase());
+        } else {
+            self.pending.push(ch);
+        }
+        self.emitted_any = true;
+    }
+}
+
+/// Fixed-capacity FIFO of pending output chars, so a single source char that
+/// expands into several output chars (transliteration, case-folding) can be
+/// drained one `next()` call at a time without allocating.
+struct PendingQueue {
+    buf: [char; PENDING_CAP],
+    start: usize,
+    len: usize,
+}
+
+impl PendingQueue {
+    const fn new() -> Self {
+        Self { buf: ['\0'; PENDING_CAP], start: 0, len: 0 }
+    }
+
+    fn push(&mut self, ch: char) {
+        debug_assert!(self.len < PENDING_CAP, "slugify_chars: pending queue overflow");
+        let idx = (self.start + self.len) % PENDING_CAP;
+        self.buf[idx] = ch;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<char> {
+        if self.len == 0 {
+            return None;
+        }
+        let ch = self.buf[self.start];
+        self.start = (self.start + 1) % PENDING_CAP;
+        self.len -= 1;
+        Some(ch)
+    }
+}
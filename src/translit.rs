@@ -3,10 +3,15 @@ use alloc::string::String;
 #[cfg(feature = "std")]
 use std::string::String;
 
+use crate::options::Romanization;
+
 /// Transliterate a single char into an ASCII-ish string.
 /// - Returns `Some(&'static str)` (or an owned string in a couple cases) for known mappings.
 /// - Returns `None` if no mapping exists.
-pub fn transliterate(ch: char, lowercase: bool) -> Option<String> {
+///
+/// `scheme` only affects Cyrillic input (see [`cyrillic_base`]); everything
+/// else goes through the shared Latin/Greek-ish table below regardless of it.
+pub fn transliterate(ch: char, lowercase: bool, scheme: Romanization) -> Option<String> {
     // fast path: ASCII handled by caller
     if ch.is_ascii() {
         return None;
@@ -16,41 +21,80 @@ pub fn transliterate(ch: char, lowercase: bool) -> Option<String> {
     // when lowercase == false.
     let is_upper = ch.is_uppercase() && !lowercase;
 
+    if ('\u{0400}'..='\u{04FF}').contains(&ch) {
+        let base = cyrillic_base(ch, scheme)?;
+        return Some(case_adjust(base, is_upper));
+    }
+
     let base: &'static str = match ch {
         // Latin-1-ish
         'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' | 'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
-        'Ð' | 'Ď' | 'Đ' | 'ð' | 'ď' | 'đ' | 'Д' | 'д' => "d",
+        'Ð' | 'Ď' | 'Đ' | 'ð' | 'ď' | 'đ' => "d",
         'Ə' | 'ə' | '€' | 'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' | 'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ'
-        | 'ė' | 'ę' | 'ě' | 'Е' | 'е' | 'Ё' | 'ё' | 'Э' | 'э' => "e",
-        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' | 'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı'
-        | 'И' | 'и' | 'І' | 'і' => "i",
-        'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'ñ' | 'ń' | 'ņ' | 'ň' | 'Н' | 'н' => "n",
+        | 'ė' | 'ę' | 'ě' => "e",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' | 'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'ñ' | 'ń' | 'ņ' | 'ň' => "n",
         '∂' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ'
-        | 'ő' | 'О' | 'о' => "o",
+        | 'ő' => "o",
         'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' | 'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů'
-        | 'ű' | 'ų' | 'У' | 'у' => "u",
-        'Ý' | 'Ÿ' | 'ý' | 'ÿ' | 'Й' | 'й' | 'Ы' | 'ы' => "y",
-        'Ł' | 'ł' | 'Л' | 'л' => "l",
-        'Ž' | 'ž' | 'Ź' | 'ź' | 'Ż' | 'ż' | 'З' | 'з' => "z",
-        '∫' | 'Š' | 'š' | 'Ś' | 'ś' | 'С' | 'с' => "s",
+        | 'ű' | 'ų' => "u",
+        'Ý' | 'Ÿ' | 'ý' | 'ÿ' => "y",
+        'Ł' | 'ł' => "l",
+        'Ž' | 'ž' | 'Ź' | 'ź' | 'Ż' | 'ż' => "z",
+        '∫' | 'Š' | 'š' | 'Ś' | 'ś' => "s",
         'Þ' | 'þ' => "th",
         // Multi-letter specials (handled below because we want owned String)
         'Æ' | 'æ' => return Some(case_adjust("ae", is_upper)),
         'Œ' | 'œ' => return Some(case_adjust("oe", is_upper)),
         'ß' => return Some("ss".into()),
 
-        // Cyrillic (rough, practical)
-        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą'
-        | 'А' | 'а' => "a",
-        'β' | 'Б' | 'б' => "b",
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => {
+            "a"
+        }
+        'β' => "b",
+
+        _ => return None,
+    };
+
+    Some(case_adjust(base, is_upper))
+}
+
+/// Per-scheme Cyrillic -> Latin base-letter mapping (before case adjustment).
+/// A soft/hard sign that a scheme knows maps to "" (dropped, with no
+/// boundary); returns `None` for any letter a given scheme doesn't know about.
+fn cyrillic_base(ch: char, scheme: Romanization) -> Option<&'static str> {
+    match scheme {
+        Romanization::Auto => cyrillic_auto(ch),
+        Romanization::RussianBgnPcgn => cyrillic_russian_bgn_pcgn(ch),
+        Romanization::UkrainianNational => cyrillic_ukrainian_national(ch),
+        Romanization::Iso9 => cyrillic_iso9(ch),
+    }
+}
+
+/// The crate's original mixed Russian/Ukrainian table, unchanged from before
+/// `scheme` existed so `Romanization::Auto` keeps producing the same output.
+fn cyrillic_auto(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'А' | 'а' => "a",
+        'Б' | 'б' => "b",
         'В' | 'в' => "v",
         'Г' | 'г' | 'Ґ' | 'ґ' => "g",
+        'Д' | 'д' => "d",
+        'Е' | 'е' | 'Ё' | 'ё' | 'Э' | 'э' => "e",
         'Ж' | 'ж' => "zh",
+        'З' | 'з' => "z",
+        'И' | 'и' | 'І' | 'і' => "i",
+        'Й' | 'й' | 'Ы' | 'ы' => "y",
         'К' | 'к' => "k",
+        'Л' | 'л' => "l",
         'М' | 'м' => "m",
+        'Н' | 'н' => "n",
+        'О' | 'о' => "o",
         'П' | 'п' => "p",
         'Р' | 'р' => "r",
+        'С' | 'с' => "s",
         'Т' | 'т' => "t",
+        'У' | 'у' => "u",
         'Ф' | 'ф' => "f",
         'Х' | 'х' => "h",
         'Ц' | 'ц' => "ts",
@@ -61,14 +105,145 @@ pub fn transliterate(ch: char, lowercase: bool) -> Option<String> {
         'Я' | 'я' => "ya",
         'Є' | 'є' => "ye",
         'Ї' | 'ї' => "yi",
+        'Ъ' | 'ъ' | 'Ь' | 'ь' => "",
+        _ => return None,
+    })
+}
 
-        // Soft/hard sign => drop (treat as boundary by returning empty)
-        'Ъ' | 'ъ' | 'Ь' | 'ь' => return Some(String::new()),
+/// BGN/PCGN romanization of Russian. Doesn't know about Ukrainian-only
+/// letters ("і", "ї", "є", "ґ") since they're not part of the Russian
+/// alphabet.
+fn cyrillic_russian_bgn_pcgn(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'А' | 'а' => "a",
+        'Б' | 'б' => "b",
+        'В' | 'в' => "v",
+        'Г' | 'г' => "g",
+        'Д' | 'д' => "d",
+        'Е' | 'е' => "e",
+        'Ё' | 'ё' => "yo",
+        'Ж' | 'ж' => "zh",
+        'З' | 'з' => "z",
+        'И' | 'и' => "i",
+        'Й' | 'й' => "y",
+        'К' | 'к' => "k",
+        'Л' | 'л' => "l",
+        'М' | 'м' => "m",
+        'Н' | 'н' => "n",
+        'О' | 'о' => "o",
+        'П' | 'п' => "p",
+        'Р' | 'р' => "r",
+        'С' | 'с' => "s",
+        'Т' | 'т' => "t",
+        'У' | 'у' => "u",
+        'Ф' | 'ф' => "f",
+        'Х' | 'х' => "kh",
+        'Ц' | 'ц' => "ts",
+        'Ч' | 'ч' => "ch",
+        'Ш' | 'ш' => "sh",
+        'Щ' | 'щ' => "shch",
+        'Ъ' | 'ъ' | 'Ь' | 'ь' => "",
+        'Ы' | 'ы' => "y",
+        'Э' | 'э' => "e",
+        'Ю' | 'ю' => "yu",
+        'Я' | 'я' => "ya",
+        _ => return None,
+    })
+}
 
+/// Ukrainian national romanization. Does not special-case word-initial
+/// "г"/"Г" (which officially romanizes as "h" everywhere but "g" after
+/// "з"/"к"/"х"/"ц" prefixes) — that needs surrounding-word context this
+/// per-char table doesn't have.
+fn cyrillic_ukrainian_national(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'А' | 'а' => "a",
+        'Б' | 'б' => "b",
+        'В' | 'в' => "v",
+        'Г' | 'г' => "h",
+        'Ґ' | 'ґ' => "g",
+        'Д' | 'д' => "d",
+        'Е' | 'е' => "e",
+        'Є' | 'є' => "ie",
+        'Ж' | 'ж' => "zh",
+        'З' | 'з' => "z",
+        'И' | 'и' => "y",
+        'І' | 'і' => "i",
+        'Ї' | 'ї' => "i",
+        'Й' | 'й' => "i",
+        'К' | 'к' => "k",
+        'Л' | 'л' => "l",
+        'М' | 'м' => "m",
+        'Н' | 'н' => "n",
+        'О' | 'о' => "o",
+        'П' | 'п' => "p",
+        'Р' | 'р' => "r",
+        'С' | 'с' => "s",
+        'Т' | 'т' => "t",
+        'У' | 'у' => "u",
+        'Ф' | 'ф' => "f",
+        'Х' | 'х' => "kh",
+        'Ц' | 'ц' => "ts",
+        'Ч' | 'ч' => "ch",
+        'Ш' | 'ш' => "sh",
+        'Щ' | 'щ' => "shch",
+        'Ь' | 'ь' => "",
+        'Ю' | 'ю' => "iu",
+        'Я' | 'я' => "ia",
         _ => return None,
-    };
+    })
+}
 
-    Some(case_adjust(base, is_upper))
+/// ISO 9 "scientific" transliteration, ASCII-folded by dropping diacritics
+/// (e.g. the real ISO 9 "ж" -> "ž" becomes "z" here). Real ISO 9 keeps every
+/// letter unique by diacritic alone ("з" -> "z" vs "ж" -> "ž"); folding the
+/// diacritic away would merge them onto the same ASCII base, so "ж"/"ч"/
+/// "ш"/"щ" get digraphs instead ("zh", "ch", "sh", "shch", keeping
+/// "з"/"ц"/"с" as the bare letter). The other diacritic-only distinctions
+/// are folded away, so those letters share an ASCII form ("е"/"ё"/"є"/"э"
+/// -> "e", "и"/"і"/"ї" -> "i", "г"/"ґ" -> "g", "у"/"ю" -> "u", "а"/"я" ->
+/// "a", "ъ"/"ь" -> "") and the output is not round-trippable.
+fn cyrillic_iso9(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'А' | 'а' => "a",
+        'Б' | 'б' => "b",
+        'В' | 'в' => "v",
+        'Г' | 'г' => "g",
+        'Ґ' | 'ґ' => "g",
+        'Д' | 'д' => "d",
+        'Е' | 'е' => "e",
+        'Ё' | 'ё' => "e",
+        'Є' | 'є' => "e",
+        'Ж' | 'ж' => "zh",
+        'З' | 'з' => "z",
+        'И' | 'и' => "i",
+        'І' | 'і' => "i",
+        'Ї' | 'ї' => "i",
+        'Й' | 'й' => "j",
+        'К' | 'к' => "k",
+        'Л' | 'л' => "l",
+        'М' | 'м' => "m",
+        'Н' | 'н' => "n",
+        'О' | 'о' => "o",
+        'П' | 'п' => "p",
+        'Р' | 'р' => "r",
+        'С' | 'с' => "s",
+        'Т' | 'т' => "t",
+        'У' | 'у' => "u",
+        'Ф' | 'ф' => "f",
+        'Х' | 'х' => "h",
+        'Ц' | 'ц' => "c",
+        'Ч' | 'ч' => "ch",
+        'Ш' | 'ш' => "sh",
+        'Щ' | 'щ' => "shch",
+        'Ъ' | 'ъ' => "",
+        'Ы' | 'ы' => "y",
+        'Ь' | 'ь' => "",
+        'Э' | 'э' => "e",
+        'Ю' | 'ю' => "u",
+        'Я' | 'я' => "a",
+        _ => return None,
+    })
 }
 
 fn case_adjust(s: &str, title_case: bool) -> String {
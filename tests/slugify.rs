@@ -1,4 +1,8 @@
-use minislug::{slugify, slugify_with, SlugOptions};
+use minislug::{slugify, slugify_chars, slugify_with, SlugOptions};
+#[cfg(feature = "detect-encoding")]
+use minislug::slugify_bytes;
+#[cfg(feature = "transliterate")]
+use minislug::Romanization;
 
 #[test]
 fn basic_ascii() {
@@ -92,6 +96,31 @@ fn max_len_bytes_truncation_is_safe_utf8() {
     assert_eq!(slugify_with("abcdef", opt), "abcde"); // ASCII
 }
 
+#[test]
+fn camel_case_word_boundary_splitting() {
+    let opt = SlugOptions {
+        split_word_case: true,
+        ..Default::default()
+    };
+    assert_eq!(slugify_with("HelloWorld", opt), "hello-world");
+    assert_eq!(slugify_with("getHTTPResponse", opt), "get-http-response");
+    // Worked example from the request body: a digit run that starts inside
+    // an acronym ("XML2") stays glued to the acronym that follows ("JSON"),
+    // rather than splitting at every digit/uppercase transition.
+    assert_eq!(slugify_with("parseXML2JSON", opt), "parse-xml2json");
+    assert_eq!(slugify_with("XMLParser", opt), "xml-parser");
+    // But a digit run that starts inside a lowercase word still splits
+    // before the next uppercase letter, per rule (a) ("previous char was
+    // lowercase/digit and current is uppercase").
+    assert_eq!(slugify_with("sha256Hash", opt), "sha256-hash");
+    assert_eq!(slugify_with("utf8Decode", opt), "utf8-decode");
+}
+
+#[test]
+fn camel_case_word_boundary_off_by_default() {
+    assert_eq!(slugify("HelloWorld"), "helloworld");
+}
+
 #[cfg(feature = "unicode")]
 #[test]
 fn unicode_kept_when_enabled_and_allowed() {
@@ -104,6 +133,35 @@ fn unicode_kept_when_enabled_and_allowed() {
     assert_eq!(slugify_with("Вещати умеют мнози, а разумети не вси", opt), "вещати-умеют-мнози-а-разумети-не-вси");
 }
 
+#[cfg(feature = "unicode")]
+#[test]
+fn case_fold_expands_sharp_s() {
+    let opt = SlugOptions { allow_unicode: true, case_fold: true, ..Default::default() };
+    assert_eq!(slugify_with("straße", opt), "strasse");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn case_fold_matches_across_case_variants() {
+    let opt = SlugOptions { allow_unicode: true, case_fold: true, ..Default::default() };
+    assert_eq!(slugify_with("Сцуко", opt), slugify_with("сЦуКо", opt));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn case_fold_off_by_default_keeps_simple_lowercase() {
+    let opt = SlugOptions { allow_unicode: true, ..Default::default() };
+    assert_eq!(slugify_with("straße", opt), "straße");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn case_fold_expands_capital_sharp_s() {
+    let opt = SlugOptions { allow_unicode: true, case_fold: true, ..Default::default() };
+    assert_eq!(slugify_with("STRA\u{1E9E}E", opt), "strasse");
+    assert_eq!(slugify_with("STRA\u{1E9E}E", opt), slugify_with("straße", opt));
+}
+
 #[cfg(not(feature = "unicode"))]
 #[cfg(not(feature = "transliterate"))]
 #[test]
@@ -132,6 +190,170 @@ fn transliteration_cyrillic() {
     assert_eq!(slugify("Киев"), "kiev");
 }
 
+#[cfg(feature = "transliterate")]
+#[test]
+fn romanization_scheme_auto_matches_legacy_default() {
+    assert_eq!(slugify("Киев"), "kiev");
+    assert_eq!(slugify_with("Киев", SlugOptions { scheme: Romanization::Auto, ..Default::default() }), "kiev");
+}
+
+#[cfg(feature = "transliterate")]
+#[test]
+fn romanization_scheme_ukrainian_national_differs_from_auto() {
+    let opt = SlugOptions { scheme: Romanization::UkrainianNational, ..Default::default() };
+    // Auto (Russian-leaning) gives "kiyiv" for "Київ"; the Ukrainian national
+    // scheme romanizes "и" as "y", "ї" as "i" and "я" as "ia".
+    assert_eq!(slugify_with("Київ", opt), "kyiv");
+    assert_eq!(slugify_with("Гривня", opt), "hryvnia");
+}
+
+#[cfg(feature = "transliterate")]
+#[test]
+fn romanization_scheme_iso9_keeps_letters_unique() {
+    let opt = SlugOptions { scheme: Romanization::Iso9, ..Default::default() };
+    // "ж"/"з" and "щ"/"с" must not collapse onto the same ASCII base: the
+    // digraph letters stay distinct from the bare letter.
+    assert_eq!(slugify_with("жук", opt), "zhuk");
+    assert_eq!(slugify_with("зук", opt), "zuk");
+    assert_eq!(slugify_with("щука", opt), "shchuka");
+    assert_eq!(slugify_with("сука", opt), "suka");
+}
+
+#[test]
+fn slugify_chars_matches_slugify_with_for_plain_ascii() {
+    let opt = SlugOptions::default();
+    let streamed: String = slugify_chars("Hello, world!".chars(), opt).collect();
+    assert_eq!(streamed, slugify_with("Hello, world!", opt));
+}
+
+#[test]
+fn slugify_chars_holds_back_trailing_separator() {
+    let opt = SlugOptions::default();
+    assert_eq!(slugify_chars("a--".chars(), opt).collect::<String>(), "a");
+    assert_eq!(slugify_chars("--a--".chars(), opt).collect::<String>(), "a");
+    assert_eq!(slugify_chars("a---b".chars(), opt).collect::<String>(), "a-b");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn slugify_chars_matches_slugify_with_for_unicode() {
+    let opt = SlugOptions { allow_unicode: true, ..Default::default() };
+    let input = "Привіт світ";
+    assert_eq!(slugify_chars(input.chars(), opt).collect::<String>(), slugify_with(input, opt));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn slugify_chars_matches_slugify_with_for_case_fold() {
+    let opt = SlugOptions { allow_unicode: true, case_fold: true, ..Default::default() };
+    let input = "straße";
+    assert_eq!(slugify_chars(input.chars(), opt).collect::<String>(), slugify_with(input, opt));
+    assert_eq!(slugify_chars(input.chars(), opt).collect::<String>(), "strasse");
+}
+
+#[cfg(feature = "transliterate")]
+#[test]
+fn slugify_chars_matches_slugify_with_for_transliteration() {
+    let opt = SlugOptions::default();
+    let input = "Crème brûlée";
+    assert_eq!(slugify_chars(input.chars(), opt).collect::<String>(), slugify_with(input, opt));
+}
+
+#[cfg(feature = "detect-encoding")]
+#[test]
+fn slugify_bytes_handles_utf8_bom() {
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend_from_slice("Hello, world!".as_bytes());
+    assert_eq!(slugify_bytes(&bytes, SlugOptions::default()), "hello-world");
+}
+
+#[cfg(feature = "detect-encoding")]
+#[test]
+fn slugify_bytes_decodes_plain_utf8() {
+    assert_eq!(slugify_bytes("Hello, world!".as_bytes(), SlugOptions::default()), "hello-world");
+}
+
+#[cfg(all(feature = "detect-encoding", feature = "transliterate"))]
+#[test]
+fn slugify_bytes_detects_windows1251() {
+    // "привет" encoded as Windows-1251.
+    let bytes: &[u8] = &[0xEF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2];
+    assert_eq!(slugify_bytes(bytes, SlugOptions::default()), "privet");
+}
+
+#[cfg(feature = "detect-encoding")]
+#[test]
+fn slugify_bytes_detects_windows1252() {
+    // "cafe" followed by a Windows-1252 right single quotation mark (0x92),
+    // with no valid Shift-JIS trail byte after it and no C1 run for
+    // Windows-1251/ISO-8859-1 to score positively, so Windows-1252 wins.
+    let bytes: &[u8] = &[0x63, 0x61, 0x66, 0x65, 0x92];
+    assert_eq!(slugify_bytes(bytes, SlugOptions::default()), "cafe");
+}
+
+#[cfg(all(feature = "detect-encoding", feature = "unicode"))]
+#[test]
+fn slugify_bytes_detects_shift_jis_half_width_katakana() {
+    // 0x8A, 0x43 look like a two-byte Shift-JIS lead/trail pair (decoded as
+    // the replacement character, since this crate's Shift-JIS support is a
+    // practical subset rather than the full JIS X 0208 table); 0xB1, 0xB2
+    // are half-width katakana "ｱｲ". Bracketing the katakana with that pair
+    // is what tips the scoring past Windows-1252's flat 0xA0-0xFF bonus.
+    let bytes: &[u8] = &[0x8A, 0x43, 0xB1, 0xB2, 0x8A, 0x43];
+    let opt = SlugOptions { allow_unicode: true, ..SlugOptions::default() };
+    assert_eq!(slugify_bytes(bytes, opt), "\u{FF71}\u{FF72}");
+}
+
+#[cfg(feature = "detect-encoding")]
+#[test]
+fn slugify_bytes_handles_utf16_le_bom() {
+    // "Hi" as UTF-16LE, BOM-prefixed.
+    let bytes: &[u8] = &[0xFF, 0xFE, 0x48, 0x00, 0x69, 0x00];
+    assert_eq!(slugify_bytes(bytes, SlugOptions::default()), "hi");
+}
+
+#[cfg(feature = "detect-encoding")]
+#[test]
+fn slugify_bytes_handles_utf16_be_bom() {
+    // "Hi" as UTF-16BE, BOM-prefixed.
+    let bytes: &[u8] = &[0xFE, 0xFF, 0x00, 0x48, 0x00, 0x69];
+    assert_eq!(slugify_bytes(bytes, SlugOptions::default()), "hi");
+}
+
+#[cfg(feature = "normalize")]
+#[test]
+fn normalize_strips_combining_marks_without_table_entries() {
+    // "Viet" with tone marks outside translit's fixed Cyrillic/Latin-1 table.
+    assert_eq!(slugify("Việt Nam"), "viet-nam");
+    assert_eq!(slugify("Nha Trang, Đắk Lắk"), "nha-trang-dak-lak");
+}
+
+#[cfg(feature = "normalize")]
+#[test]
+fn normalize_handles_precomposed_and_already_decomposed_input() {
+    let precomposed = "caf\u{00E9}"; // café, single codepoint é
+    let decomposed = "cafe\u{0301}"; // café, e + combining acute
+    assert_eq!(slugify(precomposed), slugify(decomposed));
+    assert_eq!(slugify(precomposed), "cafe");
+}
+
+#[cfg(feature = "normalize")]
+#[test]
+fn normalize_expands_compatibility_ligatures() {
+    assert_eq!(slugify("\u{FB01}le"), "file"); // "ﬁle" -> "file"
+}
+
+#[cfg(feature = "normalize")]
+#[test]
+fn normalize_preserves_case_for_latin_extended_a_s_block() {
+    // Ś/ś, Ŝ/ŝ, Ş/ş, Š/š: upper and lower must not swap.
+    let opt = SlugOptions { lowercase: false, ..Default::default() };
+    assert_eq!(slugify_with("Ś", opt), "S");
+    assert_eq!(slugify_with("ś", opt), "s");
+    assert_eq!(slugify_with("Š", opt), "S");
+    assert_eq!(slugify_with("š", opt), "s");
+}
+
 #[cfg(feature = "transliterate")]
 #[test]
 fn transliteration_respects_keep_underscore() {